@@ -1,17 +1,26 @@
 use glob::glob;
 use glob::GlobError;
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
 use regex::Regex;
 use std::collections::HashMap;
 use std::fs;
-use std::io;
 use std::io::prelude::*;
 use std::path::Path;
 use std::path::PathBuf;
+use std::sync::mpsc::channel;
+use std::time::Duration;
+use tracing::{debug, info, warn};
 
+mod error;
 mod static_files;
 
+use error::SourceBuilderError;
+
 const ROOT_FOLDER: &str = "src/routes";
 const DEV_FOLDER: &str = ".tuono";
+// Gives atomic-save editors (vim, VSCode) time to finish their
+// Remove-then-Create dance before we treat a burst of events as one change.
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(100);
 
 pub enum Mode {
     Prod,
@@ -23,13 +32,52 @@ struct Route {
     /// Every module import is the path with a _ instead of /
     pub module_import: String,
     pub axum_route: String,
+    /// HTTP methods exposed by the route file in addition to the default
+    /// `route` (page) handler, e.g. `["post", "delete"]`.
+    pub methods: Vec<String>,
+    /// Set for optional segments (`[[id]].rs`): the same handler is also
+    /// registered under this shorter path, e.g. `/foo` alongside `/foo/:id`.
+    pub optional_alias: Option<String>,
 }
 
+// Every `Regex::new` below compiles a hardcoded pattern, never one built
+// from route-file input, so a failure here would mean the pattern itself is
+// broken, not that a user's route is malformed. That's a bug to catch at
+// compile/test time, not a recoverable `SourceBuilderError` — `.expect()` is
+// kept deliberately for these, unlike the IO/glob failures elsewhere in this
+// file that do carry real user-triggerable paths.
 fn has_dynamic_path(route: &str) -> bool {
-    let regex = Regex::new(r"\[(.*?)\]").expect("Failed to create the regex");
+    // Matching the innermost bracket pair finds the dynamic token whether
+    // it's a plain `[param]`, a catch-all `[...param]` or the inner part of
+    // an optional `[[param]]` segment.
+    let regex = Regex::new(r"\[[^\[\]]*\]").expect("Failed to create the regex");
     regex.is_match(route)
 }
 
+/// Captures `slug` out of a catch-all segment like `[...slug]`.
+fn catch_all_param(route: &str) -> Option<String> {
+    let regex = Regex::new(r"\[\.\.\.([^\[\]]+)\]").expect("Failed to create the regex");
+    regex.captures(route).map(|c| c[1].to_string())
+}
+
+/// Captures `id` out of an optional segment like `[[id]]`.
+fn optional_param(route: &str) -> Option<String> {
+    let regex = Regex::new(r"\[\[([^\[\]]+)\]\]").expect("Failed to create the regex");
+    regex.captures(route).map(|c| c[1].to_string())
+}
+
+/// Finds which HTTP methods a route file exposes beyond the default `route`
+/// (page) handler, e.g. `pub fn post` or `pub async fn delete`.
+fn detect_methods(source: &str) -> Vec<String> {
+    let regex = Regex::new(r"pub\s+(?:async\s+)?fn\s+(post|put|delete|patch|head|options)\b")
+        .expect("Failed to create the regex");
+
+    regex
+        .captures_iter(source)
+        .map(|capture| capture[1].to_string())
+        .collect()
+}
+
 impl Route {
     pub fn new(path: &str) -> Self {
         let route_name = path.replace(".rs", "");
@@ -43,6 +91,45 @@ impl Route {
             return Route {
                 module_import: module.as_str().to_string().replace('/', "_"),
                 axum_route: "/".to_string(),
+                methods: Vec::new(),
+                optional_alias: None,
+            };
+        }
+
+        if let Some(param) = catch_all_param(&route_name) {
+            let bracket = format!("[...{param}]");
+            return Route {
+                module_import: module
+                    .as_str()
+                    .to_string()
+                    .replace('/', "_")
+                    .replace(&bracket, &format!("dyn_rest_{param}")),
+                axum_route: axum_route.replace(&bracket, &format!("*{param}")),
+                methods: Vec::new(),
+                optional_alias: None,
+            };
+        }
+
+        if let Some(param) = optional_param(&route_name) {
+            let bracket = format!("[[{param}]]");
+
+            let required_route = axum_route.replace(&bracket, &format!(":{param}"));
+            let optional_route = axum_route.replace(&format!("/{bracket}"), "");
+            let optional_route = if optional_route.is_empty() {
+                "/".to_string()
+            } else {
+                optional_route
+            };
+
+            return Route {
+                module_import: module
+                    .as_str()
+                    .to_string()
+                    .replace('/', "_")
+                    .replace(&bracket, &format!("dyn_{param}")),
+                axum_route: required_route,
+                methods: Vec::new(),
+                optional_alias: Some(optional_route),
             };
         }
 
@@ -55,12 +142,16 @@ impl Route {
                     .replace('[', "dyn_")
                     .replace(']', ""),
                 axum_route: axum_route.replace('[', ":").replace(']', ""),
+                methods: Vec::new(),
+                optional_alias: None,
             };
         }
 
         Route {
             module_import: module.as_str().to_string().replace('/', "_"),
             axum_route,
+            methods: Vec::new(),
+            optional_alias: None,
         }
     }
 }
@@ -72,43 +163,242 @@ struct SourceBuilder {
 }
 
 impl SourceBuilder {
-    pub fn new(mode: Mode) -> Self {
-        let base_path = std::env::current_dir().unwrap();
+    pub fn new(mode: Mode) -> Result<Self, SourceBuilderError> {
+        let base_path = std::env::current_dir().map_err(SourceBuilderError::CurrentDir)?;
 
-        SourceBuilder {
+        Ok(SourceBuilder {
             route_map: HashMap::new(),
             mode,
             base_path,
-        }
+        })
     }
 
-    fn collect_routes(&mut self) {
-        glob(self.base_path.join("src/routes/**/*.rs").to_str().unwrap())
-            .unwrap()
-            .for_each(|entry| self.collect_route(entry))
+    fn collect_routes(&mut self) -> Result<(), SourceBuilderError> {
+        let pattern = self.base_path.join("src/routes/**/*.rs");
+        let pattern_str = pattern
+            .to_str()
+            .ok_or_else(|| SourceBuilderError::InvalidRoutePath {
+                path: pattern.clone(),
+            })?;
+
+        let entries = glob(pattern_str).map_err(|source| SourceBuilderError::Glob {
+            path: pattern.clone(),
+            source,
+        })?;
+
+        for entry in entries {
+            self.collect_route(entry)?;
+        }
+
+        Ok(())
     }
 
-    fn collect_route(&mut self, path_buf: Result<PathBuf, GlobError>) {
-        let entry = path_buf.unwrap();
-        let base_path_str = self.base_path.to_str().unwrap();
+    fn collect_route(
+        &mut self,
+        path_buf: Result<PathBuf, GlobError>,
+    ) -> Result<(), SourceBuilderError> {
+        let entry = path_buf.map_err(|source| {
+            let path = source.path().to_path_buf();
+            SourceBuilderError::GlobEntry { path, source }
+        })?;
+
+        let base_path_str =
+            self.base_path
+                .to_str()
+                .ok_or_else(|| SourceBuilderError::InvalidRoutePath {
+                    path: self.base_path.clone(),
+                })?;
+
         let path = entry
             .to_str()
-            .unwrap()
+            .ok_or_else(|| SourceBuilderError::InvalidRoutePath {
+                path: entry.clone(),
+            })?
             .replace(&format!("{base_path_str}/src/routes"), "");
 
-        let route = Route::new(&path);
+        let mut route = Route::new(&path);
+        // Best-effort: an unreadable route file just means we can't detect
+        // its extra methods, not that the whole build should fail.
+        route.methods = fs::read_to_string(&entry)
+            .map(|source| detect_methods(&source))
+            .unwrap_or_default();
 
         self.route_map.insert(PathBuf::from(path), route);
+
+        Ok(())
+    }
+
+    /// Re-globs the whole routes tree and regenerates `.tuono/main.rs`.
+    ///
+    /// Routes are re-collected from scratch rather than patched incrementally:
+    /// atomic-save editors emit Remove/Rename-then-Create sequences for a
+    /// single edit, so diffing individual file events is more fragile than
+    /// just re-scanning the tree.
+    fn regenerate_main_file(&mut self) -> Result<(), SourceBuilderError> {
+        self.route_map.clear();
+        self.collect_routes()?;
+        debug!(count = self.route_map.len(), "collected routes");
+
+        let conflicts = detect_route_conflicts(&self.route_map);
+        for conflict in &conflicts {
+            warn!("{conflict}");
+        }
+        // Dev mode favors availability: a conflict during a rename/edit is
+        // surfaced but shouldn't kill the live-reload loop. Prod builds fail
+        // fast instead of shipping a nondeterministic routing table.
+        if matches!(self.mode, Mode::Prod) {
+            if let Some(conflict) = conflicts.into_iter().next() {
+                return Err(conflict);
+            }
+        }
+
+        let bundled_file = static_files::AXUM_ENTRY_POINT
+            .replace(
+                "// ROUTE_BUILDER\n",
+                &create_routes_declaration(&self.route_map),
+            )
+            .replace(
+                "// MODULE_IMPORTS\n",
+                &create_modules_declaration(&self.route_map)?,
+            );
+
+        create_main_file(&self.base_path, &bundled_file)
     }
+
+    /// Watches `src/routes` and regenerates `.tuono/main.rs` whenever a
+    /// route file is added, removed or edited.
+    ///
+    /// Events are debounced over [`WATCH_DEBOUNCE`] so a burst of filesystem
+    /// events (e.g. an editor's save-to-temp-then-rename) triggers a single
+    /// regeneration. The parent directory is watched recursively instead of
+    /// individual file handles, since some editors replace the watched file
+    /// entirely on save.
+    fn watch_routes(&mut self) -> Result<(), SourceBuilderError> {
+        let watch_path = self.base_path.join(ROOT_FOLDER);
+        let to_watch_error = |source| SourceBuilderError::Watch {
+            path: watch_path.clone(),
+            source,
+        };
+
+        let (tx, rx) = channel();
+        let mut watcher: RecommendedWatcher =
+            notify::recommended_watcher(tx).map_err(to_watch_error)?;
+
+        watcher
+            .watch(&watch_path, RecursiveMode::Recursive)
+            .map_err(to_watch_error)?;
+
+        info!("watching {ROOT_FOLDER} for changes");
+
+        loop {
+            let Ok(event) = rx.recv() else {
+                break;
+            };
+
+            // Drain any further events within the debounce window so a burst
+            // of Remove/Create/Modify events collapses into one regeneration.
+            // Atomic-save editors write a throwaway temp file before the
+            // real Rename/Create of the `.rs` file, so relevance has to be
+            // checked across the whole burst, not just its first event.
+            let mut relevant = is_relevant_event(&event);
+            while let Ok(event) = rx.recv_timeout(WATCH_DEBOUNCE) {
+                relevant = relevant || is_relevant_event(&event);
+            }
+
+            if relevant {
+                self.regenerate_main_file()?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn is_relevant_event(event: &notify::Result<notify::Event>) -> bool {
+    let Ok(event) = event else {
+        return false;
+    };
+
+    matches!(
+        event.kind,
+        EventKind::Create(_) | EventKind::Remove(_) | EventKind::Modify(_)
+    ) && event
+        .paths
+        .iter()
+        .any(|path| path.extension().is_some_and(|ext| ext == "rs"))
 }
 
-fn create_main_file(base_path: &Path, bundled_file: &String) {
-    let mut data_file =
-        fs::File::create(base_path.join(".tuono/main.rs")).expect("creation failed");
+fn create_main_file(base_path: &Path, bundled_file: &str) -> Result<(), SourceBuilderError> {
+    let path = base_path.join(".tuono/main.rs");
+    let to_write_error = |source| SourceBuilderError::WriteFile {
+        path: path.clone(),
+        source,
+    };
+
+    let mut data_file = fs::File::create(&path).map_err(to_write_error)?;
 
     data_file
         .write_all(bundled_file.as_bytes())
-        .expect("write failed");
+        .map_err(to_write_error)?;
+
+    Ok(())
+}
+
+/// Finds route files that would collide in the generated router: two files
+/// resolving to the same axum path (an optional segment's alias included),
+/// or two files resolving to the same module import.
+fn detect_route_conflicts(routes: &HashMap<PathBuf, Route>) -> Vec<SourceBuilderError> {
+    let mut by_axum_route: HashMap<&str, Vec<&PathBuf>> = HashMap::new();
+    let mut by_module_import: HashMap<&str, Vec<&PathBuf>> = HashMap::new();
+
+    for (path, route) in routes {
+        by_axum_route
+            .entry(route.axum_route.as_str())
+            .or_default()
+            .push(path);
+        if let Some(alias) = &route.optional_alias {
+            by_axum_route.entry(alias.as_str()).or_default().push(path);
+        }
+        by_module_import
+            .entry(route.module_import.as_str())
+            .or_default()
+            .push(path);
+    }
+
+    let mut conflicts = Vec::new();
+
+    // Sort both the groups and each group's paths so the reported pairs (and
+    // their order) are stable across runs instead of following HashMap's
+    // arbitrary iteration order.
+    let mut axum_route_groups: Vec<_> = by_axum_route.into_iter().collect();
+    axum_route_groups.sort_unstable_by_key(|(axum_route, _)| *axum_route);
+
+    for (axum_route, mut paths) in axum_route_groups {
+        paths.sort_unstable();
+        for pair in paths.windows(2) {
+            conflicts.push(SourceBuilderError::ConflictingAxumRoute {
+                path_a: pair[0].clone(),
+                path_b: pair[1].clone(),
+                axum_route: axum_route.to_string(),
+            });
+        }
+    }
+
+    let mut module_import_groups: Vec<_> = by_module_import.into_iter().collect();
+    module_import_groups.sort_unstable_by_key(|(module_import, _)| *module_import);
+
+    for (module_import, mut paths) in module_import_groups {
+        paths.sort_unstable();
+        for pair in paths.windows(2) {
+            conflicts.push(SourceBuilderError::ConflictingModuleImport {
+                path_a: pair[0].clone(),
+                path_b: pair[1].clone(),
+                module_import: module_import.to_string(),
+            });
+        }
+    }
+
+    conflicts
 }
 
 fn create_routes_declaration(routes: &HashMap<PathBuf, Route>) -> String {
@@ -118,25 +408,41 @@ fn create_routes_declaration(routes: &HashMap<PathBuf, Route>) -> String {
         let Route {
             axum_route,
             module_import,
+            methods,
+            optional_alias,
         } = &route;
 
-        route_declarations.push_str(&format!(
-            r#".route("{axum_route}", get({module_import}::route))"#
-        ));
+        let mut handler = format!("get({module_import}::route)");
+        for method in methods {
+            handler.push_str(&format!(".{method}({module_import}::{method})"));
+        }
+
+        route_declarations.push_str(&format!(r#".route("{axum_route}", {handler})"#));
         route_declarations.push_str(&format!(
             r#".route("/__tuono/data{axum_route}", get({module_import}::api))"#
         ));
+
+        if let Some(alias) = optional_alias {
+            route_declarations.push_str(&format!(r#".route("{alias}", {handler})"#));
+            route_declarations.push_str(&format!(
+                r#".route("/__tuono/data{alias}", get({module_import}::api))"#
+            ));
+        }
     }
 
     route_declarations
 }
 
-fn create_modules_declaration(routes: &HashMap<PathBuf, Route>) -> String {
+fn create_modules_declaration(
+    routes: &HashMap<PathBuf, Route>,
+) -> Result<String, SourceBuilderError> {
     let mut route_declarations = String::from("// MODULE_IMPORTS\n");
 
     for (path, route) in routes.iter() {
         let module_name = &route.module_import;
-        let path_str = path.to_str().unwrap();
+        let path_str = path
+            .to_str()
+            .ok_or_else(|| SourceBuilderError::InvalidRoutePath { path: path.clone() })?;
         route_declarations.push_str(&format!(
             r#"#[path="../{ROOT_FOLDER}{path_str}"]
 mod {module_name};
@@ -144,51 +450,67 @@ mod {module_name};
         ))
     }
 
-    route_declarations
+    Ok(route_declarations)
 }
 
-pub fn bundle_axum_source() -> io::Result<()> {
-    println!("Axum project bundling");
-
-    let base_path = std::env::current_dir().unwrap();
+pub fn bundle_axum_source(mode: Mode) -> Result<(), SourceBuilderError> {
+    info!("axum project bundling");
 
-    let mut source_builder = SourceBuilder::new(Mode::Dev);
+    let mut source_builder = SourceBuilder::new(mode)?;
 
-    source_builder.collect_routes();
+    source_builder.regenerate_main_file()?;
 
-    let bundled_file = static_files::AXUM_ENTRY_POINT
-        .replace(
-            "// ROUTE_BUILDER\n",
-            &create_routes_declaration(&source_builder.route_map),
-        )
-        .replace(
-            "// MODULE_IMPORTS\n",
-            &create_modules_declaration(&source_builder.route_map),
-        );
-
-    create_main_file(&base_path, &bundled_file);
+    if matches!(source_builder.mode, Mode::Dev) {
+        if let Err(err) = source_builder.watch_routes() {
+            warn!("failed to watch {ROOT_FOLDER}: {err}");
+        }
+    }
 
     Ok(())
 }
 
-pub fn check_tuono_folder() -> io::Result<()> {
+pub fn check_tuono_folder() -> Result<(), SourceBuilderError> {
     let dev_folder = Path::new(DEV_FOLDER);
-    if !&dev_folder.is_dir() {
-        println!("exists");
-        fs::create_dir(dev_folder)?;
+    if !dev_folder.is_dir() {
+        debug!("{DEV_FOLDER} folder not found, creating it");
+        fs::create_dir(dev_folder).map_err(|source| SourceBuilderError::CreateTuonoFolder {
+            path: dev_folder.to_path_buf(),
+            source,
+        })?;
     }
 
     Ok(())
 }
 
-pub fn create_client_entry_files() -> io::Result<()> {
+pub fn create_client_entry_files() -> Result<(), SourceBuilderError> {
     let dev_folder = Path::new(DEV_FOLDER);
 
-    let mut server_entry = fs::File::create(dev_folder.join("server-main.tsx"))?;
-    let mut client_entry = fs::File::create(dev_folder.join("client-main.tsx"))?;
-
-    server_entry.write_all(static_files::SERVER_ENTRY_DATA.as_bytes())?;
-    client_entry.write_all(static_files::CLIENT_ENTRY_DATA.as_bytes())?;
+    let server_entry_path = dev_folder.join("server-main.tsx");
+    let client_entry_path = dev_folder.join("client-main.tsx");
+
+    let mut server_entry =
+        fs::File::create(&server_entry_path).map_err(|source| SourceBuilderError::WriteFile {
+            path: server_entry_path.clone(),
+            source,
+        })?;
+    let mut client_entry =
+        fs::File::create(&client_entry_path).map_err(|source| SourceBuilderError::WriteFile {
+            path: client_entry_path.clone(),
+            source,
+        })?;
+
+    server_entry
+        .write_all(static_files::SERVER_ENTRY_DATA.as_bytes())
+        .map_err(|source| SourceBuilderError::WriteFile {
+            path: server_entry_path,
+            source,
+        })?;
+    client_entry
+        .write_all(static_files::CLIENT_ENTRY_DATA.as_bytes())
+        .map_err(|source| SourceBuilderError::WriteFile {
+            path: client_entry_path,
+            source,
+        })?;
 
     Ok(())
 }
@@ -211,6 +533,14 @@ mod tests {
                 "/home/user/Documents/tuono/src/routes/posts/[post].rs",
                 true,
             ),
+            (
+                "/home/user/Documents/tuono/src/routes/posts/[...slug].rs",
+                true,
+            ),
+            (
+                "/home/user/Documents/tuono/src/routes/posts/[[id]].rs",
+                true,
+            ),
         ];
 
         routes
@@ -220,7 +550,7 @@ mod tests {
 
     #[test]
     fn collect_routes() {
-        let mut source_builder = SourceBuilder::new(Mode::Dev);
+        let mut source_builder = SourceBuilder::new(Mode::Dev).unwrap();
         source_builder.base_path = "/home/user/Documents/tuono".into();
 
         let routes = [
@@ -230,9 +560,11 @@ mod tests {
             "/home/user/Documents/tuono/src/routes/posts/[post].rs",
         ];
 
-        routes
-            .into_iter()
-            .for_each(|route| source_builder.collect_route(Ok(PathBuf::from(route))));
+        routes.into_iter().for_each(|route| {
+            source_builder
+                .collect_route(Ok(PathBuf::from(route)))
+                .unwrap()
+        });
 
         let results = [
             ("/index.rs", "index"),
@@ -253,9 +585,95 @@ mod tests {
         })
     }
 
+    #[test]
+    fn detect_methods_from_source() {
+        let source = r#"
+            pub fn route() {}
+            pub async fn post() {}
+            pub fn delete() {}
+        "#;
+
+        assert_eq!(detect_methods(source), vec!["post", "delete"]);
+    }
+
+    #[test]
+    fn chain_extra_methods_in_routes_declaration() {
+        let mut routes = HashMap::new();
+        routes.insert(
+            PathBuf::from("/posts.rs"),
+            Route {
+                module_import: "posts".to_string(),
+                axum_route: "/posts".to_string(),
+                methods: vec!["post".to_string(), "delete".to_string()],
+                optional_alias: None,
+            },
+        );
+
+        let declaration = create_routes_declaration(&routes);
+
+        assert!(declaration.contains(
+            r#".route("/posts", get(posts::route).post(posts::post).delete(posts::delete))"#
+        ));
+    }
+
+    #[test]
+    fn detect_conflicting_axum_routes() {
+        let mut routes = HashMap::new();
+        routes.insert(
+            PathBuf::from("/posts/index.rs"),
+            Route {
+                module_import: "posts_index".to_string(),
+                axum_route: "/posts".to_string(),
+                methods: Vec::new(),
+                optional_alias: None,
+            },
+        );
+        routes.insert(
+            PathBuf::from("/posts.rs"),
+            Route {
+                module_import: "posts".to_string(),
+                axum_route: "/posts".to_string(),
+                methods: Vec::new(),
+                optional_alias: None,
+            },
+        );
+
+        let conflicts = detect_route_conflicts(&routes);
+
+        assert_eq!(conflicts.len(), 1);
+        assert!(matches!(
+            conflicts[0],
+            SourceBuilderError::ConflictingAxumRoute { .. }
+        ));
+    }
+
+    #[test]
+    fn conflict_report_is_deterministic() {
+        let mut routes = HashMap::new();
+        for path in ["/posts/z.rs", "/posts/a.rs", "/posts/m.rs"] {
+            routes.insert(
+                PathBuf::from(path),
+                Route {
+                    module_import: path.to_string(),
+                    axum_route: "/posts".to_string(),
+                    methods: Vec::new(),
+                    optional_alias: None,
+                },
+            );
+        }
+
+        let first_run = detect_route_conflicts(&routes);
+        let second_run = detect_route_conflicts(&routes);
+
+        assert_eq!(first_run.len(), 2);
+        for (a, b) in first_run.iter().zip(second_run.iter()) {
+            assert_eq!(a.to_string(), b.to_string());
+        }
+    }
+
     #[test]
     fn create_multi_level_axum_paths() {
-        let mut source_builder = SourceBuilder::new(Mode::Dev);
+        let mut source_builder = SourceBuilder::new(Mode::Dev).unwrap();
         source_builder.base_path = "/home/user/Documents/tuono".into();
 
         let routes = [
@@ -264,11 +682,15 @@ mod tests {
             "/home/user/Documents/tuono/src/routes/posts/index.rs",
             "/home/user/Documents/tuono/src/routes/posts/any-post.rs",
             "/home/user/Documents/tuono/src/routes/posts/[post].rs",
+            "/home/user/Documents/tuono/src/routes/posts/[...slug].rs",
+            "/home/user/Documents/tuono/src/routes/posts/[[id]].rs",
         ];
 
-        routes
-            .into_iter()
-            .for_each(|route| source_builder.collect_route(Ok(PathBuf::from(route))));
+        routes.into_iter().for_each(|route| {
+            source_builder
+                .collect_route(Ok(PathBuf::from(route)))
+                .unwrap()
+        });
 
         let results = [
             ("/index.rs", "/"),
@@ -276,6 +698,8 @@ mod tests {
             ("/posts/index.rs", "/posts"),
             ("/posts/any-post.rs", "/posts/any-post"),
             ("/posts/[post].rs", "/posts/:post"),
+            ("/posts/[...slug].rs", "/posts/*slug"),
+            ("/posts/[[id]].rs", "/posts/:id"),
         ];
 
         results.into_iter().for_each(|(path, expected_path)| {
@@ -289,4 +713,22 @@ mod tests {
             )
         })
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn catch_all_module_import() {
+        let route = Route::new("/posts/[...slug].rs");
+
+        assert_eq!(route.module_import, "posts_dyn_rest_slug");
+        assert_eq!(route.axum_route, "/posts/*slug");
+        assert_eq!(route.optional_alias, None);
+    }
+
+    #[test]
+    fn optional_segment_registers_both_routes() {
+        let route = Route::new("/posts/[[id]].rs");
+
+        assert_eq!(route.module_import, "posts_dyn_id");
+        assert_eq!(route.axum_route, "/posts/:id");
+        assert_eq!(route.optional_alias, Some("/posts".to_string()));
+    }
+}
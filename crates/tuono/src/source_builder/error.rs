@@ -0,0 +1,77 @@
+use std::io;
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+/// Errors produced while collecting routes and bundling the axum entry
+/// point.
+///
+/// Every IO/glob failure except reading the current directory carries the
+/// path it failed on (a route file, the glob pattern, the routes folder or
+/// the `.tuono` output) so users can locate the problem instead of staring
+/// at a generic panic message.
+#[derive(Debug, Error)]
+pub enum SourceBuilderError {
+    #[error("could not glob route files in {}: {source}", path.display())]
+    Glob {
+        path: PathBuf,
+        #[source]
+        source: glob::PatternError,
+    },
+
+    #[error("could not read route file {}: {source}", path.display())]
+    GlobEntry {
+        path: PathBuf,
+        #[source]
+        source: glob::GlobError,
+    },
+
+    #[error("invalid route path {}: not valid UTF-8", path.display())]
+    InvalidRoutePath { path: PathBuf },
+
+    #[error("could not read the current directory: {0}")]
+    CurrentDir(#[source] io::Error),
+
+    #[error("could not create the {} folder: {source}", path.display())]
+    CreateTuonoFolder {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("could not write {}: {source}", path.display())]
+    WriteFile {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("could not watch {}: {source}", path.display())]
+    Watch {
+        path: PathBuf,
+        #[source]
+        source: notify::Error,
+    },
+
+    #[error(
+        "route conflict: {} and {} both resolve to axum route \"{axum_route}\"",
+        path_a.display(),
+        path_b.display()
+    )]
+    ConflictingAxumRoute {
+        path_a: PathBuf,
+        path_b: PathBuf,
+        axum_route: String,
+    },
+
+    #[error(
+        "route conflict: {} and {} both resolve to module import \"{module_import}\"",
+        path_a.display(),
+        path_b.display()
+    )]
+    ConflictingModuleImport {
+        path_a: PathBuf,
+        path_b: PathBuf,
+        module_import: String,
+    },
+}